@@ -2,17 +2,64 @@
 // rolling-median
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+// --------------------------------------------------------------------------
+// NaN error
+// --------------------------------------------------------------------------
+
+/// Error returned when a `NaN` value is rejected, e.g. by [`FloatOrd::new()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NanError;
+
+impl fmt::Display for NanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Value must not be NaN!")
+    }
+}
+
+impl std::error::Error for NanError {}
 
 // --------------------------------------------------------------------------
 // Float type
 // --------------------------------------------------------------------------
 
-/// Generic floating-point type, e.g, `f32` or `f64`
-pub trait FloatType: Copy + Clone {
+/// Generic sample type accepted by this crate, e.g, `f32`, `f64`, or any integer type
+pub trait Sample: Copy + Clone {
+    /// Type yielded by [`midpoint()`](Sample::midpoint) / [`promote()`](Sample::promote)
+    ///
+    /// This is `Self` for floating-point types, and `f64` for integer types, so that averaging
+    /// two integer samples (e.g. for an even-count median) can represent a fractional result.
+    type Midpoint: Copy;
+
     fn cmp(&self, other: &Self) -> Ordering;
     fn is_nan(self) -> bool;
-    fn midpoint(self, other: Self) -> Self;
+    fn midpoint(self, other: Self) -> Self::Midpoint;
+    fn promote(self) -> Self::Midpoint;
+    fn write_hash<H: Hasher>(&self, state: &mut H);
+
+    /// Canonical `NaN` value of [`Midpoint`](Sample::Midpoint), used to propagate `NaN`
+    fn midpoint_nan() -> Self::Midpoint;
+
+    /// Truncates a [`Midpoint`](Sample::Midpoint) back down to `Self`
+    ///
+    /// This is the identity for floating-point types; for integer types it truncates towards
+    /// zero (as an `as` cast would), letting callers opt out of the `f64` promotion that
+    /// [`midpoint()`](Sample::midpoint) otherwise applies to an even-count median.
+    fn truncate(midpoint: Self::Midpoint) -> Self;
+
+    /// Averages two samples, truncated towards zero, without promoting to [`Midpoint`](Sample::Midpoint)
+    ///
+    /// For floating-point types this is equivalent to `truncate(midpoint(self, other))`. For
+    /// integer types it instead computes the average directly in `Self`, via the overflow-safe
+    /// floor average `(a & b) + ((a ^ b) >> 1)` (moved up by one for a negative odd sum), so it
+    /// stays exact for values beyond `f64`'s 53-bit mantissa instead of round-tripping through a
+    /// lossy `f64` promotion.
+    fn midpoint_truncated(self, other: Self) -> Self;
 
     #[inline]
     fn eq(self, other: &Self) -> bool {
@@ -28,10 +75,11 @@ pub trait FloatType: Copy + Clone {
     }
 }
 
-impl FloatType for f32 {
+impl Sample for f32 {
+    type Midpoint = f32;
+
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        assert!(!(self.is_nan() || other.is_nan()), "Value must not be NaN!");
         if *self == *other {
             return Ordering::Equal;
         }
@@ -44,19 +92,45 @@ impl FloatType for f32 {
     }
 
     #[inline]
-    fn midpoint(self, other: Self) -> Self {
-        assert!(!(self.is_nan() || other.is_nan()), "Value must not be NaN!");
+    fn midpoint(self, other: Self) -> Self::Midpoint {
         match f32::midpoint(self, other) {
             value if value.is_nan() => f32::default(),
             value => value,
         }
     }
+
+    #[inline]
+    fn promote(self) -> Self::Midpoint {
+        self
+    }
+
+    #[inline]
+    fn write_hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = if *self == 0.0f32 { 0.0f32 } else { *self };
+        normalized.to_bits().hash(state);
+    }
+
+    #[inline]
+    fn midpoint_nan() -> Self::Midpoint {
+        f32::NAN
+    }
+
+    #[inline]
+    fn truncate(midpoint: Self::Midpoint) -> Self {
+        midpoint
+    }
+
+    #[inline]
+    fn midpoint_truncated(self, other: Self) -> Self {
+        Self::truncate(self.midpoint(other))
+    }
 }
 
-impl FloatType for f64 {
+impl Sample for f64 {
+    type Midpoint = f64;
+
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        assert!(!(self.is_nan() || other.is_nan()), "Value must not be NaN!");
         if *self == *other {
             return Ordering::Equal;
         }
@@ -69,50 +143,174 @@ impl FloatType for f64 {
     }
 
     #[inline]
-    fn midpoint(self, other: Self) -> Self {
-        assert!(!(self.is_nan() || other.is_nan()), "Value must not be NaN!");
+    fn midpoint(self, other: Self) -> Self::Midpoint {
         match f64::midpoint(self, other) {
             value if value.is_nan() => f64::default(),
             value => value,
         }
     }
+
+    #[inline]
+    fn promote(self) -> Self::Midpoint {
+        self
+    }
+
+    #[inline]
+    fn write_hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = if *self == 0.0f64 { 0.0f64 } else { *self };
+        normalized.to_bits().hash(state);
+    }
+
+    #[inline]
+    fn midpoint_nan() -> Self::Midpoint {
+        f64::NAN
+    }
+
+    #[inline]
+    fn truncate(midpoint: Self::Midpoint) -> Self {
+        midpoint
+    }
+
+    #[inline]
+    fn midpoint_truncated(self, other: Self) -> Self {
+        Self::truncate(self.midpoint(other))
+    }
 }
 
+/// Implements [`Sample`] for an integer type, promoting to `f64` for `midpoint`/`promote`.
+///
+/// `midpoint`/`promote` go through `f64`, so averaging two samples beyond `f64`'s 53-bit mantissa
+/// can lose precision; `midpoint_truncated` avoids this for the truncated API by computing the
+/// overflow-safe integer average, truncated towards zero, directly in `Self`, without ever
+/// promoting to `f64`.
+macro_rules! impl_float_type_for_int {
+    ($($int:ty),+ $(,)?) => {
+        $(
+            impl Sample for $int {
+                type Midpoint = f64;
+
+                #[inline]
+                fn cmp(&self, other: &Self) -> Ordering {
+                    Ord::cmp(self, other)
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    false
+                }
+
+                #[inline]
+                fn midpoint(self, other: Self) -> Self::Midpoint {
+                    (self as f64 + other as f64) / 2.0
+                }
+
+                #[inline]
+                fn promote(self) -> Self::Midpoint {
+                    self as f64
+                }
+
+                #[inline]
+                fn write_hash<H: Hasher>(&self, state: &mut H) {
+                    Hash::hash(self, state);
+                }
+
+                #[inline]
+                fn midpoint_nan() -> Self::Midpoint {
+                    f64::NAN
+                }
+
+                #[inline]
+                fn truncate(midpoint: Self::Midpoint) -> Self {
+                    midpoint as $int
+                }
+
+                #[inline]
+                fn midpoint_truncated(self, other: Self) -> Self {
+                    let floor = (self & other) + ((self ^ other) >> 1);
+                    if (self ^ other) & 1 != 0 && floor < 0 as $int { floor + 1 } else { floor }
+                }
+            }
+        )+
+    };
+}
+
+impl_float_type_for_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 // --------------------------------------------------------------------------
 // Ordered float
 // --------------------------------------------------------------------------
 
-/// Ordered floating-point wrapper type, extends on `FloatType`
+/// Ordered floating-point wrapper type, extends on `Sample`
 #[derive(Debug, Clone, Copy)]
-pub struct FloatOrd<T: FloatType>(pub T);
+pub struct FloatOrd<T: Sample>(pub T);
 
-impl<T: FloatType> From<T> for FloatOrd<T> {
+impl<T: Sample> FloatOrd<T> {
+    /// Wraps `value`, rejecting `NaN` rather than panicking
+    #[inline]
+    pub fn new(value: T) -> Result<Self, NanError> {
+        if value.is_nan() {
+            Err(NanError)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Unwraps the underlying value
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Sample> From<T> for FloatOrd<T> {
     #[inline]
     fn from(value: T) -> Self {
-        assert!(!value.is_nan(), "Value must not be NaN!");
-        Self(value)
+        Self::new(value).expect("Value must not be NaN!")
     }
 }
 
-impl<T: FloatType> PartialEq for FloatOrd<T> {
+impl<T: Sample> PartialEq for FloatOrd<T> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.0.eq(&other.0)
     }
 }
 
-impl<T: FloatType> Eq for FloatOrd<T> {}
+impl<T: Sample> Eq for FloatOrd<T> {}
 
-impl<T: FloatType> PartialOrd for FloatOrd<T> {
+impl<T: Sample> PartialOrd for FloatOrd<T> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T: FloatType> Ord for FloatOrd<T> {
+impl<T: Sample> Ord for FloatOrd<T> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         self.0.cmp(&other.0)
     }
 }
+
+impl<T: Sample> Hash for FloatOrd<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.write_hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Sample + serde::Serialize> serde::Serialize for FloatOrd<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Sample + serde::Deserialize<'de>> serde::Deserialize<'de> for FloatOrd<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
@@ -39,65 +39,187 @@
 //! }
 //! ```
 
-use ordered_float::{FloatCore, OrderedFloat};
-use std::{cmp::Reverse, collections::BinaryHeap};
+pub mod float_utils;
+
+mod quantile;
+mod windowed;
+pub use float_utils::NanError;
+pub use quantile::Quantile;
+pub use windowed::WindowedMedian;
+
+use float_utils::Sample;
+
+// --------------------------------------------------------------------------
+// NaN policy
+// --------------------------------------------------------------------------
+
+/// Controls how [`Median::try_push()`] (and the panicking [`Median::push()`]) handle `NaN`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NanPolicy {
+    /// Reject the `NaN` sample, returning [`NanError`] from `try_push()`
+    #[default]
+    Reject,
+    /// Silently ignore the `NaN` sample, leaving the estimator unchanged
+    Skip,
+    /// Accept the `NaN` sample; `get()` returns `NaN` from this point onward
+    Propagate,
+}
 
 // --------------------------------------------------------------------------
 // Rolling median
 // --------------------------------------------------------------------------
 
 /// Computes the median of a data set, using a "rolling" (online) algorithm
-pub struct Median<T: FloatCore> {
-    heap_lo: BinaryHeap<OrderedFloat<T>>,
-    heap_hi: BinaryHeap<Reverse<OrderedFloat<T>>>,
+///
+/// This is the `p = 0.5` special case of [`Quantile`]. `T` may be a floating-point type (`f32`,
+/// `f64`) or any integer type; see [`float_utils::Sample`] for details.
+///
+/// With the `serde` feature enabled, this can be serialized and deserialized to checkpoint a
+/// long-running estimator across restarts; deserialization re-validates the heap balance
+/// invariant (see [`Quantile`]'s `Deserialize` impl), rejecting a corrupted snapshot.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Median<T: Sample> {
+    quantile: Quantile<T>,
+    policy: NanPolicy,
+    poisoned: bool,
 }
 
-impl<T: FloatCore> Median<T> {
-    /// Initializes a new rolling median computation
+impl<T: Sample> Median<T> {
+    /// Initializes a new rolling median computation, rejecting `NaN` samples
     pub fn new() -> Self {
-        Median { heap_lo: BinaryHeap::new(), heap_hi: BinaryHeap::new() }
+        Self::with_policy(NanPolicy::default())
     }
 
-    /// Insert the next value
+    /// Initializes a new rolling median computation with the given [`NanPolicy`]
+    pub fn with_policy(policy: NanPolicy) -> Self {
+        Median { quantile: Quantile::new(0.5), policy, poisoned: false }
+    }
+
+    /// Insert the next value, handling `NaN` according to this estimator's [`NanPolicy`]
     ///
     /// This operation has a complexity of **O(log(n))**.
-    pub fn push(&mut self, value: T) {
-        if self.heap_lo.peek().is_none_or(|peek| value <= peek.0) {
-            self.heap_lo.push(value.into());
-        } else {
-            self.heap_hi.push(Reverse(value.into()));
+    pub fn try_push(&mut self, value: T) -> Result<(), NanError> {
+        if value.is_nan() {
+            return match self.policy {
+                NanPolicy::Reject => Err(NanError),
+                NanPolicy::Skip => Ok(()),
+                NanPolicy::Propagate => {
+                    self.poisoned = true;
+                    Ok(())
+                }
+            };
         }
 
-        if self.heap_lo.len() > self.heap_hi.len().checked_add(1usize).unwrap() {
-            if let Some(value) = self.heap_lo.pop() {
-                self.heap_hi.push(Reverse(value));
-            }
-        } else if self.heap_hi.len() > self.heap_lo.len() {
-            if let Some(Reverse(value)) = self.heap_hi.pop() {
-                self.heap_lo.push(value);
-            }
-        }
+        self.quantile.push(value);
+        Ok(())
+    }
+
+    /// Insert the next value
+    ///
+    /// This is a convenience wrapper around [`try_push()`](Median::try_push) that panics where
+    /// `try_push()` returns an error; under [`NanPolicy::Skip`] and [`NanPolicy::Propagate`], a
+    /// `NaN` is handled as in `try_push()` and does not panic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is `NaN` and the policy is [`NanPolicy::Reject`].
+    pub fn push(&mut self, value: T) {
+        self.try_push(value).expect("Value must not be NaN!");
     }
 
     /// Get the current median
     ///
     /// This operation has a complexity of **O(1)**.
-    pub fn get(&self) -> Option<T> {
-        if self.heap_lo.is_empty() {
-            None
-        } else if self.heap_lo.len() == self.heap_hi.len() {
-            let lo_top = *self.heap_lo.peek().unwrap();
-            let hi_top = self.heap_hi.peek().unwrap().0;
-            Some((lo_top.0 + hi_top.0) / T::from(2).unwrap())
+    pub fn get(&self) -> Option<T::Midpoint> {
+        if self.poisoned {
+            Some(T::midpoint_nan())
         } else {
-            Some(self.heap_lo.peek().unwrap().0)
+            self.quantile.get()
+        }
+    }
+
+    /// Get the current median, truncated to the original sample type `T`
+    ///
+    /// Unlike [`get()`](Median::get), this never promotes to `T::Midpoint` (`f64` for integer
+    /// `T`); see [`Quantile::get_truncated()`](crate::Quantile::get_truncated).
+    ///
+    /// This operation has a complexity of **O(1)**.
+    pub fn get_truncated(&self) -> Option<T> {
+        if self.poisoned {
+            Some(T::truncate(T::midpoint_nan()))
+        } else {
+            self.quantile.get_truncated()
+        }
+    }
+
+    /// Builds a new rolling median directly from a batch of already-collected samples
+    ///
+    /// Rather than pushing the samples one at a time (**O(n log(n))**), this partitions them
+    /// around the true median and heapifies each side, for a total complexity of **O(n)**. The
+    /// resulting estimator uses the default [`NanPolicy`] and rejects the whole batch, rather than
+    /// an individual sample, if any sample is `NaN`.
+    pub fn from_values<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, NanError> {
+        let quantile = Quantile::from_values(0.5, iter)?;
+        Ok(Median { quantile, policy: NanPolicy::default(), poisoned: false })
+    }
+
+    /// Builds a new rolling median directly from a slice of already-collected samples
+    ///
+    /// This is a convenience wrapper around [`from_values()`](Median::from_values); see there for
+    /// details.
+    pub fn from_slice(values: &[T]) -> Result<Self, NanError> {
+        Self::from_values(values.iter().copied())
+    }
+
+    /// Merges `other` into `self`, combining both estimators' accumulated samples into one
+    ///
+    /// This enables parallel/sharded median computation: split a huge stream across threads,
+    /// accumulate a `Median` per shard, then fold the shards together with `merge()`. The
+    /// combined result is exact, not an approximation, since no information is discarded; this
+    /// operation has a complexity of **O(n)** in the combined sample count.
+    ///
+    /// The merged estimator keeps `self`'s [`NanPolicy`], and is poisoned (see [`NanPolicy::Propagate`])
+    /// if either `self` or `other` was poisoned.
+    pub fn merge(self, other: Self) -> Self {
+        Median {
+            quantile: self.quantile.merge(other.quantile),
+            policy: self.policy,
+            poisoned: self.poisoned || other.poisoned,
         }
     }
 }
 
-impl<T: FloatCore> Default for Median<T> {
+impl<T: Sample> Default for Median<T> {
     /// Initializes a new rolling median computation
     fn default() -> Self {
         Self::new()
     }
 }
+
+// --------------------------------------------------------------------------
+// Serde support
+// --------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MedianSnapshot<T: Sample> {
+    quantile: Quantile<T>,
+    policy: NanPolicy,
+    poisoned: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Sample + serde::Deserialize<'de>> serde::Deserialize<'de> for Median<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let snapshot = MedianSnapshot::<T>::deserialize(deserializer)?;
+
+        if snapshot.quantile.p() != 0.5 {
+            return Err(D::Error::custom("Invalid snapshot: `Median` requires `p == 0.5`!"));
+        }
+
+        Ok(Median { quantile: snapshot.quantile, policy: snapshot.policy, poisoned: snapshot.poisoned })
+    }
+}
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: 0BSD
+// rolling-median
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use crate::float_utils::{FloatOrd, NanError, Sample};
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+// --------------------------------------------------------------------------
+// Rolling quantile
+// --------------------------------------------------------------------------
+
+/// Computes an arbitrary quantile of a data set, using a "rolling" (online) algorithm
+///
+/// This generalizes the two-heap median scheme to any quantile `p` in the range `[0.0, 1.0]`,
+/// e.g. `p = 0.9` tracks the running 90th percentile. [`Median`](crate::Median) is the
+/// `p = 0.5` special case of this estimator.
+pub struct Quantile<T: Sample> {
+    p: f64,
+    count: usize,
+    heap_lo: BinaryHeap<FloatOrd<T>>,
+    heap_hi: BinaryHeap<Reverse<FloatOrd<T>>>,
+}
+
+impl<T: Sample> Quantile<T> {
+    /// Initializes a new rolling quantile computation for the given quantile `p`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is outside of the range `[0.0, 1.0]`.
+    pub fn new(p: f64) -> Self {
+        assert!((0.0..=1.0).contains(&p), "Quantile must be in the range [0.0, 1.0]!");
+        Quantile { p, count: 0usize, heap_lo: BinaryHeap::new(), heap_hi: BinaryHeap::new() }
+    }
+
+    /// Insert the next value
+    ///
+    /// This operation has a complexity of **O(log(n))**.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is `NaN`. Unlike [`Median`](crate::Median), `Quantile` has no
+    /// non-panicking `try_push()` and no configurable [`NanPolicy`](crate::NanPolicy).
+    pub fn push(&mut self, value: T) {
+        let wrapped: FloatOrd<T> = value.into();
+        if self.heap_lo.peek().is_none_or(|peek| wrapped <= *peek) {
+            self.heap_lo.push(wrapped);
+        } else {
+            self.heap_hi.push(Reverse(wrapped));
+        }
+
+        self.count += 1usize;
+        let target = target_size(self.p, self.count);
+
+        while self.heap_lo.len() > target {
+            if let Some(value) = self.heap_lo.pop() {
+                self.heap_hi.push(Reverse(value));
+            }
+        }
+        while self.heap_lo.len() < target {
+            if let Some(Reverse(value)) = self.heap_hi.pop() {
+                self.heap_lo.push(value);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Get the current quantile estimate
+    ///
+    /// This operation has a complexity of **O(1)**.
+    pub fn get(&self) -> Option<T::Midpoint> {
+        if self.count == 0usize {
+            return None;
+        }
+
+        if self.rank_fract() > 0.0 {
+            let lo_top = self.heap_lo.peek().unwrap().0;
+            let hi_top = self.heap_hi.peek().unwrap().0.0;
+            Some(lo_top.midpoint(hi_top))
+        } else {
+            Some(self.heap_lo.peek().unwrap().0.promote())
+        }
+    }
+
+    /// Get the current quantile estimate, truncated to the original sample type `T`
+    ///
+    /// Unlike [`get()`](Quantile::get), this never promotes to `T::Midpoint` (`f64` for integer
+    /// `T`): the exact-rank case returns the original sample as-is, and the interpolated case is
+    /// averaged directly in `T` via [`Sample::midpoint_truncated()`], so this stays exact for
+    /// integer samples beyond `f64`'s 53-bit mantissa.
+    ///
+    /// This operation has a complexity of **O(1)**.
+    pub fn get_truncated(&self) -> Option<T> {
+        if self.count == 0usize {
+            return None;
+        }
+
+        if self.rank_fract() > 0.0 {
+            let lo_top = self.heap_lo.peek().unwrap().0;
+            let hi_top = self.heap_hi.peek().unwrap().0.0;
+            Some(lo_top.midpoint_truncated(hi_top))
+        } else {
+            Some(self.heap_lo.peek().unwrap().0)
+        }
+    }
+
+    /// The quantile `p` this estimator was constructed with
+    #[cfg(feature = "serde")]
+    pub(crate) fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Builds a new quantile estimator directly from a batch of already-collected samples
+    ///
+    /// Rather than pushing the samples one at a time (**O(n log(n))**), this partitions them
+    /// around the target rank and heapifies each side, for a total complexity of **O(n)**.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is outside of the range `[0.0, 1.0]`.
+    pub fn from_values<I: IntoIterator<Item = T>>(p: f64, iter: I) -> Result<Self, NanError> {
+        assert!((0.0..=1.0).contains(&p), "Quantile must be in the range [0.0, 1.0]!");
+        let values: Vec<FloatOrd<T>> = iter.into_iter().map(FloatOrd::new).collect::<Result<_, _>>()?;
+        Ok(Self::from_wrapped(p, values))
+    }
+
+    /// Builds a new quantile estimator directly from a slice of already-collected samples
+    ///
+    /// This is a convenience wrapper around [`from_values()`](Quantile::from_values); see there
+    /// for details.
+    pub fn from_slice(p: f64, values: &[T]) -> Result<Self, NanError> {
+        Self::from_values(p, values.iter().copied())
+    }
+
+    /// Merges `other` into `self`, combining both estimators' accumulated values into one
+    ///
+    /// This drains both sides' heaps and rebuilds the estimator from scratch in **O(n)**, so the
+    /// combined result is exact, not an approximation: no information is discarded. This enables
+    /// parallel/sharded quantile computation: split a huge stream across threads, accumulate one
+    /// estimator per shard, then fold the shards together with `merge()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `other` were constructed for different quantiles `p`.
+    pub fn merge(self, other: Self) -> Self {
+        assert_eq!(self.p, other.p, "Cannot merge quantile estimators tracking different quantiles!");
+
+        let mut values = Vec::with_capacity(self.count + other.count);
+        values.extend(self.heap_lo);
+        values.extend(self.heap_hi.into_iter().map(|Reverse(value)| value));
+        values.extend(other.heap_lo);
+        values.extend(other.heap_hi.into_iter().map(|Reverse(value)| value));
+
+        Self::from_wrapped(self.p, values)
+    }
+
+    /// Heapifies already-[`FloatOrd`]-wrapped values around the target rank, in **O(n)**
+    ///
+    /// Partitions `values` via `select_nth_unstable()`, then heapifies each side via
+    /// `BinaryHeap::from()` - the shared implementation behind [`from_values()`](Quantile::from_values)
+    /// and [`merge()`](Quantile::merge).
+    fn from_wrapped(p: f64, mut values: Vec<FloatOrd<T>>) -> Self {
+        let count = values.len();
+        let target = target_size(p, count);
+
+        if 0usize < target && target < count {
+            values.select_nth_unstable(target - 1usize);
+        }
+        let hi_values = values.split_off(target);
+
+        let heap_lo = BinaryHeap::from(values);
+        let heap_hi = BinaryHeap::from(hi_values.into_iter().map(Reverse).collect::<Vec<_>>());
+
+        Quantile { p, count, heap_lo, heap_hi }
+    }
+
+    /// Fractional part of the (0-indexed) desired rank `p * (count - 1)`
+    ///
+    /// A non-zero fractional part means the desired rank falls strictly between two order
+    /// statistics, in which case the tops of `heap_lo` and `heap_hi` are averaged.
+    fn rank_fract(&self) -> f64 {
+        let ideal = self.p * ((self.count - 1usize) as f64);
+        ideal - ideal.floor()
+    }
+}
+
+/// Target size of `heap_lo` so that its top holds the order statistic at the desired rank
+fn target_size(p: f64, count: usize) -> usize {
+    if count == 0usize {
+        return 0usize;
+    }
+    let ideal = p * ((count - 1usize) as f64);
+    (ideal.floor() as isize + 1isize).clamp(0isize, count as isize) as usize
+}
+
+// --------------------------------------------------------------------------
+// Serde support
+// --------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QuantileSnapshot<T> {
+    p: f64,
+    count: usize,
+    heap_lo: Vec<T>,
+    heap_hi: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Sample + serde::Serialize> serde::Serialize for Quantile<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let snapshot = QuantileSnapshot {
+            p: self.p,
+            count: self.count,
+            heap_lo: self.heap_lo.iter().map(|wrapped| wrapped.into_inner()).collect(),
+            heap_hi: self.heap_hi.iter().map(|Reverse(wrapped)| wrapped.into_inner()).collect(),
+        };
+        snapshot.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Sample + serde::Deserialize<'de>> serde::Deserialize<'de> for Quantile<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let snapshot = QuantileSnapshot::<T>::deserialize(deserializer)?;
+
+        let heap_lo: BinaryHeap<FloatOrd<T>> = snapshot
+            .heap_lo
+            .into_iter()
+            .map(|value| FloatOrd::new(value).map_err(D::Error::custom))
+            .collect::<Result<_, _>>()?;
+        let heap_hi: BinaryHeap<Reverse<FloatOrd<T>>> = snapshot
+            .heap_hi
+            .into_iter()
+            .map(|value| FloatOrd::new(value).map(Reverse).map_err(D::Error::custom))
+            .collect::<Result<_, _>>()?;
+
+        if !(0.0..=1.0).contains(&snapshot.p) {
+            return Err(D::Error::custom("Invalid snapshot: `p` is out of range [0.0, 1.0]!"));
+        }
+        if snapshot.count != heap_lo.len().saturating_add(heap_hi.len()) {
+            return Err(D::Error::custom("Invalid snapshot: `count` does not match the heap sizes!"));
+        }
+        if heap_lo.len() != target_size(snapshot.p, snapshot.count) {
+            return Err(D::Error::custom("Invalid snapshot: heap size invariant violated!"));
+        }
+        if let (Some(lo_top), Some(Reverse(hi_top))) = (heap_lo.peek(), heap_hi.peek()) {
+            if lo_top > hi_top {
+                return Err(D::Error::custom("Invalid snapshot: heap ordering invariant violated!"));
+            }
+        }
+
+        Ok(Quantile { p: snapshot.p, count: snapshot.count, heap_lo, heap_hi })
+    }
+}
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: 0BSD
+// rolling-median
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use crate::float_utils::{FloatOrd, Sample};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+// --------------------------------------------------------------------------
+// Windowed (sliding-window) median
+// --------------------------------------------------------------------------
+
+/// Computes the median over a fixed-size sliding window of the most recently pushed values
+///
+/// Internally this uses the same two-heap design as [`Median`](crate::Median), plus *lazy
+/// deletion*: the value that falls out of the window is merely scheduled for removal and is
+/// only actually popped once it reaches the top of its heap.
+pub struct WindowedMedian<T: Sample> {
+    window: usize,
+    heap_lo: BinaryHeap<FloatOrd<T>>,
+    heap_hi: BinaryHeap<Reverse<FloatOrd<T>>>,
+    order: VecDeque<FloatOrd<T>>,
+    pending: HashMap<FloatOrd<T>, usize>,
+    balance: isize,
+}
+
+impl<T: Sample> WindowedMedian<T> {
+    /// Initializes a new windowed median computation over the last `window` pushed values
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0usize, "Window size must be greater than zero!");
+        WindowedMedian {
+            window,
+            heap_lo: BinaryHeap::new(),
+            heap_hi: BinaryHeap::new(),
+            order: VecDeque::with_capacity(window),
+            pending: HashMap::new(),
+            balance: 0isize,
+        }
+    }
+
+    /// Insert the next value, evicting the oldest value once the window is full
+    ///
+    /// This operation has an amortized complexity of **O(log(k))**.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is `NaN`. Unlike [`Median`](crate::Median), `WindowedMedian` has no
+    /// non-panicking `try_push()` and no configurable [`NanPolicy`](crate::NanPolicy).
+    pub fn push(&mut self, value: T) {
+        if self.order.len() >= self.window {
+            let expired = self.order.pop_front().unwrap();
+            self.schedule_removal(expired);
+        }
+
+        let wrapped: FloatOrd<T> = value.into();
+        self.order.push_back(wrapped);
+
+        if self.heap_lo.peek().is_none_or(|peek| wrapped <= *peek) {
+            self.heap_lo.push(wrapped);
+            self.balance += 1isize;
+        } else {
+            self.heap_hi.push(Reverse(wrapped));
+            self.balance -= 1isize;
+        }
+
+        self.prune();
+        self.rebalance();
+    }
+
+    /// Get the current median, over the values currently held in the window
+    ///
+    /// This operation has a complexity of **O(1)**.
+    pub fn get(&self) -> Option<T::Midpoint> {
+        if self.order.is_empty() {
+            None
+        } else if self.balance == 0isize {
+            let lo_top = self.heap_lo.peek().unwrap().0;
+            let hi_top = self.heap_hi.peek().unwrap().0.0;
+            Some(lo_top.midpoint(hi_top))
+        } else {
+            Some(self.heap_lo.peek().unwrap().0.promote())
+        }
+    }
+
+    /// Get the current median, truncated to the original sample type `T`, over the values
+    /// currently held in the window
+    ///
+    /// Unlike [`get()`](WindowedMedian::get), this never promotes to `T::Midpoint` (`f64` for
+    /// integer `T`): the odd-count case returns the original sample as-is, and the even-count
+    /// case is averaged directly in `T` via [`Sample::midpoint_truncated()`](crate::float_utils::Sample::midpoint_truncated),
+    /// so this stays exact for integer samples beyond `f64`'s 53-bit mantissa.
+    ///
+    /// This operation has a complexity of **O(1)**.
+    pub fn get_truncated(&self) -> Option<T> {
+        if self.order.is_empty() {
+            return None;
+        }
+
+        if self.balance == 0isize {
+            let lo_top = self.heap_lo.peek().unwrap().0;
+            let hi_top = self.heap_hi.peek().unwrap().0.0;
+            Some(lo_top.midpoint_truncated(hi_top))
+        } else {
+            Some(self.heap_lo.peek().unwrap().0)
+        }
+    }
+
+    /// Schedule the value that just fell out of the window for (lazy) deletion
+    fn schedule_removal(&mut self, value: FloatOrd<T>) {
+        *self.pending.entry(value).or_insert(0usize) += 1usize;
+
+        let lives_in_lo = self.heap_lo.peek().is_none_or(|peek| value <= *peek);
+        if lives_in_lo {
+            self.balance -= 1isize;
+        } else {
+            self.balance += 1isize;
+        }
+    }
+
+    /// Pop values off the top of either heap while they are marked as pending deletion
+    fn prune(&mut self) {
+        while let Some(top) = self.heap_lo.peek().copied() {
+            if !self.discard_pending(&top) {
+                break;
+            }
+            self.heap_lo.pop();
+        }
+
+        while let Some(Reverse(top)) = self.heap_hi.peek().copied() {
+            if !self.discard_pending(&top) {
+                break;
+            }
+            self.heap_hi.pop();
+        }
+    }
+
+    /// Decrement the pending-deletion count for `value`, if any; returns whether it was pending
+    fn discard_pending(&mut self, value: &FloatOrd<T>) -> bool {
+        match self.pending.get_mut(value) {
+            Some(count) => {
+                *count -= 1usize;
+                if *count == 0usize {
+                    self.pending.remove(value);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move elements across the two heaps until `balance` is restored to `{0, 1}`
+    fn rebalance(&mut self) {
+        while self.balance > 1isize {
+            let Some(value) = self.heap_lo.pop() else { break };
+            self.heap_hi.push(Reverse(value));
+            self.balance -= 2isize;
+            self.prune();
+        }
+
+        while self.balance < 0isize {
+            let Some(Reverse(value)) = self.heap_hi.pop() else { break };
+            self.heap_lo.push(value);
+            self.balance += 2isize;
+            self.prune();
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: 0BSD
+// rolling-median
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use rand_pcg::{
+    rand_core::{SeedableRng, TryRngCore},
+    Pcg64,
+};
+use rolling_median::Median;
+
+// --------------------------------------------------------------------------
+// Utility functions
+// --------------------------------------------------------------------------
+
+fn compute_median(values: &[u64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let len = values.len();
+    let mut values = values.to_vec();
+    values.sort();
+    let (mid, rem) = (len / 2usize, len % 2usize);
+
+    if rem == 0usize {
+        Some(((values[mid - 1] as f64) + (values[mid] as f64)) / 2.0)
+    } else {
+        Some(values[mid] as f64)
+    }
+}
+
+fn random_values(seed: u64, count: usize) -> Vec<u64> {
+    let mut random = Pcg64::seed_from_u64(seed);
+    (0..count).map(|_| random.try_next_u64().unwrap()).collect()
+}
+
+fn do_from_slice_test(seed: u64, count: usize) {
+    let all_values = random_values(seed, count);
+    let median: Median<u64> = Median::from_slice(&all_values).unwrap();
+    assert_eq!(compute_median(&all_values), median.get());
+}
+
+fn do_merge_test(seed: u64, count_a: usize, count_b: usize) {
+    let values_a = random_values(seed, count_a);
+    let values_b = random_values(seed.wrapping_add(1u64), count_b);
+
+    let median_a: Median<u64> = Median::from_slice(&values_a).unwrap();
+    let median_b: Median<u64> = Median::from_slice(&values_b).unwrap();
+    let merged = median_a.merge(median_b);
+
+    let all_values: Vec<u64> = values_a.into_iter().chain(values_b).collect();
+    assert_eq!(compute_median(&all_values), merged.get());
+}
+
+// --------------------------------------------------------------------------
+// Tests
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_from_slice_empty() {
+    let median: Median<u64> = Median::from_slice(&[]).unwrap();
+    assert_eq!(median.get(), None);
+}
+
+#[test]
+fn test_from_slice_rejects_nan() {
+    assert!(Median::<f64>::from_slice(&[1.0, f64::NAN, 2.0]).is_err());
+}
+
+#[test]
+fn test_from_slice_1() {
+    do_from_slice_test(0u64, 1usize);
+}
+
+#[test]
+fn test_from_slice_2() {
+    do_from_slice_test(1u64, 2usize);
+}
+
+#[test]
+fn test_from_slice_3() {
+    do_from_slice_test(2u64, 3usize);
+}
+
+#[test]
+fn test_from_slice_997() {
+    do_from_slice_test(0u64, 997usize);
+}
+
+#[test]
+fn test_from_slice_998() {
+    do_from_slice_test(1u64, 998usize);
+}
+
+#[test]
+fn test_merge_both_empty() {
+    let merged: Median<u64> = Median::from_slice(&[]).unwrap().merge(Median::from_slice(&[]).unwrap());
+    assert_eq!(merged.get(), None);
+}
+
+#[test]
+fn test_merge_one_empty() {
+    do_merge_test(0u64, 0usize, 500usize);
+}
+
+#[test]
+fn test_merge_even_odd() {
+    do_merge_test(1u64, 123usize, 456usize);
+}
+
+#[test]
+fn test_merge_uneven_shards() {
+    do_merge_test(2u64, 1usize, 999usize);
+}
+
+#[test]
+fn test_merge_propagates_poisoned_state() {
+    use rolling_median::NanPolicy;
+
+    let mut poisoned: Median<f64> = Median::with_policy(NanPolicy::Propagate);
+    poisoned.push(1.0);
+    poisoned.try_push(f64::NAN).unwrap();
+
+    let healthy: Median<f64> = Median::from_slice(&[2.0, 3.0]).unwrap();
+
+    let merged = healthy.merge(poisoned);
+    assert!(merged.get().unwrap().is_nan());
+}
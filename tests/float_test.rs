@@ -3,13 +3,13 @@
 // Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
 
 use itertools::Itertools;
-use rolling_median::float_utils::{FloatOrd, FloatType};
+use rolling_median::float_utils::{FloatOrd, Sample};
 
 // --------------------------------------------------------------------------
 // Utility functions
 // --------------------------------------------------------------------------
 
-fn assert_arrays_equal<T: FloatType>(array_a: &[FloatOrd<T>], array_b: &[T]) {
+fn assert_arrays_equal<T: Sample + PartialEq + std::fmt::Debug>(array_a: &[FloatOrd<T>], array_b: &[T]) {
     assert_eq!(array_a.len(), array_b.len());
     for (a, b) in array_a.iter().map(|val| val.into_inner()).zip(array_b.iter().copied()) {
         assert_eq!(a, b)
@@ -22,62 +22,62 @@ fn assert_arrays_equal<T: FloatType>(array_a: &[FloatOrd<T>], array_b: &[T]) {
 
 #[test]
 fn test_float_0a() {
-    assert!(!FloatType::is_nan(0.0f32));
-    assert!(!FloatType::is_nan(0.0f64));
+    assert!(!Sample::is_nan(0.0f32));
+    assert!(!Sample::is_nan(0.0f64));
 }
 
 #[test]
 fn test_float_0b() {
-    assert!(!FloatType::is_nan(f32::MAX));
-    assert!(!FloatType::is_nan(f64::MAX));
+    assert!(!Sample::is_nan(f32::MAX));
+    assert!(!Sample::is_nan(f64::MAX));
 }
 
 #[test]
 fn test_float_0c() {
-    assert!(!FloatType::is_nan(f32::MIN));
-    assert!(!FloatType::is_nan(f64::MIN));
+    assert!(!Sample::is_nan(f32::MIN));
+    assert!(!Sample::is_nan(f64::MIN));
 }
 
 #[test]
 fn test_float_0d() {
-    assert!(!FloatType::is_nan(f32::INFINITY));
-    assert!(!FloatType::is_nan(f64::INFINITY));
+    assert!(!Sample::is_nan(f32::INFINITY));
+    assert!(!Sample::is_nan(f64::INFINITY));
 }
 
 #[test]
 fn test_float_0e() {
-    assert!(!FloatType::is_nan(f32::NEG_INFINITY));
-    assert!(!FloatType::is_nan(f64::NEG_INFINITY));
+    assert!(!Sample::is_nan(f32::NEG_INFINITY));
+    assert!(!Sample::is_nan(f64::NEG_INFINITY));
 }
 
 #[test]
 fn test_float_0f() {
-    assert!(FloatType::is_nan(f32::NAN));
-    assert!(FloatType::is_nan(f32::NAN));
+    assert!(Sample::is_nan(f32::NAN));
+    assert!(Sample::is_nan(f32::NAN));
 }
 
 #[test]
 fn test_float_1a() {
-    assert_eq!(FloatType::midpoint(0.0f32, 0.0f32), 0.0f32);
-    assert_eq!(FloatType::midpoint(0.0f64, 0.0f64), 0.0f64);
+    assert_eq!(Sample::midpoint(0.0f32, 0.0f32), 0.0f32);
+    assert_eq!(Sample::midpoint(0.0f64, 0.0f64), 0.0f64);
 }
 
 #[test]
 fn test_float_1b() {
-    assert_eq!(FloatType::midpoint(1.0f32, -1.0f32), 0.0f32);
-    assert_eq!(FloatType::midpoint(1.0f64, -1.0f64), 0.0f64);
+    assert_eq!(Sample::midpoint(1.0f32, -1.0f32), 0.0f32);
+    assert_eq!(Sample::midpoint(1.0f64, -1.0f64), 0.0f64);
 }
 
 #[test]
 fn test_float_1c() {
-    assert_eq!(FloatType::midpoint(1.0f32, 2.0f32), 1.5f32);
-    assert_eq!(FloatType::midpoint(1.0f64, 2.0f64), 1.5f64);
+    assert_eq!(Sample::midpoint(1.0f32, 2.0f32), 1.5f32);
+    assert_eq!(Sample::midpoint(1.0f64, 2.0f64), 1.5f64);
 }
 
 #[test]
 fn test_float_1d() {
-    assert_eq!(FloatType::midpoint(-1.0f32, -2.0f32), -1.5f32);
-    assert_eq!(FloatType::midpoint(-1.0f64, -2.0f64), -1.5f64);
+    assert_eq!(Sample::midpoint(-1.0f32, -2.0f32), -1.5f32);
+    assert_eq!(Sample::midpoint(-1.0f64, -2.0f64), -1.5f64);
 }
 
 #[test]
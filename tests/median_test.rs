@@ -12,13 +12,13 @@ use rolling_median::Median;
 // Utility functions
 // --------------------------------------------------------------------------
 
-fn compute_median(values: &Vec<u64>) -> Option<f64> {
+fn compute_median(values: &[u64]) -> Option<f64> {
     if values.is_empty() {
         return None;
     }
 
     let len = values.len();
-    let mut values = values.clone();
+    let mut values = values.to_vec();
     values.sort();
     let (mid, rem) = (len / 2usize, len % 2usize);
 
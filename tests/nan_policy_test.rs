@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: 0BSD
+// rolling-median
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use rolling_median::{Median, NanPolicy};
+
+// --------------------------------------------------------------------------
+// Tests
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_nan_reject_is_default() {
+    let mut median: Median<f64> = Median::new();
+    median.push(1.0);
+    assert!(median.try_push(f64::NAN).is_err());
+    assert_eq!(median.get(), Some(1.0));
+}
+
+#[test]
+fn test_nan_skip() {
+    let mut median: Median<f64> = Median::with_policy(NanPolicy::Skip);
+    median.push(1.0);
+    median.push(3.0);
+    assert!(median.try_push(f64::NAN).is_ok());
+    assert_eq!(median.get(), Some(2.0));
+}
+
+#[test]
+fn test_nan_propagate() {
+    let mut median: Median<f64> = Median::with_policy(NanPolicy::Propagate);
+    median.push(1.0);
+    assert!(median.try_push(f64::NAN).is_ok());
+    assert!(median.get().unwrap().is_nan());
+
+    median.push(2.0);
+    assert!(median.get().unwrap().is_nan());
+}
+
+#[test]
+#[should_panic(expected = "Value must not be NaN!")]
+fn test_nan_push_panics() {
+    let mut median: Median<f64> = Median::new();
+    median.push(f64::NAN);
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: 0BSD
+// rolling-median
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use rand_pcg::{
+    rand_core::{SeedableRng, TryRngCore},
+    Pcg64,
+};
+use rolling_median::Quantile;
+
+// --------------------------------------------------------------------------
+// Utility functions
+// --------------------------------------------------------------------------
+
+fn compute_quantile(values: &[u32], p: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut values = values.to_vec();
+    values.sort();
+
+    let ideal = p * ((values.len() - 1usize) as f64);
+    let floor_idx = ideal.floor() as usize;
+    let frac = ideal - (floor_idx as f64);
+
+    if frac > 0.0 {
+        Some(((values[floor_idx] as f64) + (values[floor_idx + 1usize] as f64)) / 2.0)
+    } else {
+        Some(values[floor_idx] as f64)
+    }
+}
+
+fn do_test(seed: u64, count: usize, p: f64) {
+    let mut quantile: Quantile<f64> = Quantile::new(p);
+    let mut all_values: Vec<u32> = Vec::with_capacity(count);
+    let mut random = Pcg64::seed_from_u64(seed);
+
+    for _ in 0..count {
+        let value = random.try_next_u32().unwrap();
+        quantile.push(value as f64);
+        all_values.push(value);
+    }
+
+    assert_eq!(compute_quantile(&all_values, p), quantile.get());
+}
+
+// --------------------------------------------------------------------------
+// Tests
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_quantile_0() {
+    do_test(0u64, 0usize, 0.9);
+}
+
+#[test]
+fn test_quantile_1a() {
+    do_test(0u64, 1usize, 0.9);
+}
+
+#[test]
+fn test_quantile_1b() {
+    do_test(1u64, 1usize, 0.1);
+}
+
+#[test]
+fn test_quantile_2a() {
+    do_test(0u64, 997usize, 0.9);
+}
+
+#[test]
+fn test_quantile_2b() {
+    do_test(1u64, 997usize, 0.5);
+}
+
+#[test]
+fn test_quantile_2c() {
+    do_test(2u64, 997usize, 0.99);
+}
+
+#[test]
+fn test_quantile_3a() {
+    do_test(0u64, 998usize, 0.1);
+}
+
+#[test]
+fn test_quantile_3b() {
+    do_test(1u64, 998usize, 0.5);
+}
+
+#[test]
+fn test_quantile_4a() {
+    do_test(0u64, 500usize, 0.0);
+}
+
+#[test]
+fn test_quantile_4b() {
+    do_test(0u64, 500usize, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "Quantile must be in the range")]
+fn test_quantile_5() {
+    let _: Quantile<f64> = Quantile::new(1.5);
+}
+
+fn do_from_values_test(seed: u64, count: usize, p: f64) {
+    let mut random = Pcg64::seed_from_u64(seed);
+    let all_values: Vec<u32> = (0..count).map(|_| random.try_next_u32().unwrap()).collect();
+
+    let quantile: Quantile<f64> = Quantile::from_values(p, all_values.iter().map(|value| *value as f64)).unwrap();
+    assert_eq!(compute_quantile(&all_values, p), quantile.get());
+}
+
+#[test]
+fn test_quantile_from_values_empty() {
+    let quantile: Quantile<f64> = Quantile::from_values(0.9, []).unwrap();
+    assert_eq!(quantile.get(), None);
+}
+
+#[test]
+fn test_quantile_from_values_rejects_nan() {
+    assert!(Quantile::<f64>::from_values(0.9, [1.0, f64::NAN, 2.0]).is_err());
+}
+
+#[test]
+fn test_quantile_from_values_997() {
+    do_from_values_test(0u64, 997usize, 0.9);
+}
+
+#[test]
+fn test_quantile_from_values_998() {
+    do_from_values_test(1u64, 998usize, 0.1);
+}
+
+#[test]
+fn test_quantile_merge() {
+    let mut random = Pcg64::seed_from_u64(0u64);
+    let values_a: Vec<u32> = (0..123usize).map(|_| random.try_next_u32().unwrap()).collect();
+    let values_b: Vec<u32> = (0..456usize).map(|_| random.try_next_u32().unwrap()).collect();
+
+    let quantile_a: Quantile<f64> = Quantile::from_values(0.9, values_a.iter().map(|value| *value as f64)).unwrap();
+    let quantile_b: Quantile<f64> = Quantile::from_values(0.9, values_b.iter().map(|value| *value as f64)).unwrap();
+    let merged = quantile_a.merge(quantile_b);
+
+    let all_values: Vec<u32> = values_a.into_iter().chain(values_b).collect();
+    assert_eq!(compute_quantile(&all_values, 0.9), merged.get());
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: 0BSD
+// rolling-median
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+#![cfg(feature = "serde")]
+
+use rolling_median::Median;
+
+// --------------------------------------------------------------------------
+// Tests
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_roundtrip_preserves_median() {
+    let mut median: Median<f64> = Median::new();
+    for value in [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0] {
+        median.push(value);
+    }
+
+    let json = serde_json::to_string(&median).unwrap();
+    let restored: Median<f64> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.get(), median.get());
+}
+
+#[test]
+fn test_roundtrip_resumes_pushing() {
+    let mut median: Median<f64> = Median::new();
+    for value in [1.0, 2.0, 3.0] {
+        median.push(value);
+    }
+
+    let json = serde_json::to_string(&median).unwrap();
+    let mut restored: Median<f64> = serde_json::from_str(&json).unwrap();
+    restored.push(4.0);
+
+    let mut expected: Median<f64> = Median::new();
+    for value in [1.0, 2.0, 3.0, 4.0] {
+        expected.push(value);
+    }
+
+    assert_eq!(restored.get(), expected.get());
+}
+
+#[test]
+fn test_corrupted_snapshot_is_rejected() {
+    let json = r#"{"quantile":{"p":0.5,"count":3,"heap_lo":[1.0],"heap_hi":[3.0]},"policy":"Reject","poisoned":false}"#;
+    assert!(serde_json::from_str::<Median<f64>>(json).is_err());
+}
+
+#[test]
+fn test_snapshot_with_misordered_heaps_is_rejected() {
+    let json = r#"{"p":0.5,"count":2,"heap_lo":[100.0],"heap_hi":[1.0]}"#;
+    assert!(serde_json::from_str::<rolling_median::Quantile<f64>>(json).is_err());
+}
+
+#[test]
+fn test_snapshot_with_wrong_p_is_rejected() {
+    let json = r#"{"quantile":{"p":0.9,"count":1,"heap_lo":[5.0],"heap_hi":[]},"policy":"Reject","poisoned":false}"#;
+    assert!(serde_json::from_str::<Median<f64>>(json).is_err());
+}
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: 0BSD
+// rolling-median
+// Copyright (C) 2025-2026 by LoRd_MuldeR <mulder2@gmx.de>
+
+use rand_pcg::{
+    rand_core::{SeedableRng, TryRngCore},
+    Pcg64,
+};
+use rolling_median::WindowedMedian;
+use std::collections::VecDeque;
+
+// --------------------------------------------------------------------------
+// Utility functions
+// --------------------------------------------------------------------------
+
+fn compute_median(values: &VecDeque<u32>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let len = values.len();
+    let mut values: Vec<u32> = values.iter().copied().collect();
+    values.sort();
+    let (mid, rem) = (len / 2usize, len % 2usize);
+
+    if rem == 0usize {
+        Some(((values[mid - 1] as f64) + (values[mid] as f64)) / 2.0)
+    } else {
+        Some(values[mid] as f64)
+    }
+}
+
+fn do_test(seed: u64, window: usize, count: usize) {
+    let mut median: WindowedMedian<f64> = WindowedMedian::new(window);
+    let mut recent: VecDeque<u32> = VecDeque::with_capacity(window);
+    let mut random = Pcg64::seed_from_u64(seed);
+
+    for _ in 0..count {
+        let value = random.try_next_u32().unwrap();
+        median.push(value as f64);
+
+        if recent.len() >= window {
+            recent.pop_front();
+        }
+        recent.push_back(value);
+
+        assert_eq!(compute_median(&recent), median.get());
+    }
+}
+
+// --------------------------------------------------------------------------
+// Tests
+// --------------------------------------------------------------------------
+
+#[test]
+fn test_windowed_0() {
+    do_test(0u64, 3usize, 0usize);
+}
+
+#[test]
+fn test_windowed_1a() {
+    do_test(0u64, 1usize, 10usize);
+}
+
+#[test]
+fn test_windowed_1b() {
+    do_test(1u64, 1usize, 10usize);
+}
+
+#[test]
+fn test_windowed_2a() {
+    do_test(0u64, 5usize, 997usize);
+}
+
+#[test]
+fn test_windowed_2b() {
+    do_test(1u64, 5usize, 997usize);
+}
+
+#[test]
+fn test_windowed_3a() {
+    do_test(0u64, 4usize, 998usize);
+}
+
+#[test]
+fn test_windowed_3b() {
+    do_test(1u64, 4usize, 998usize);
+}
+
+#[test]
+fn test_windowed_4() {
+    do_test(0u64, 101usize, 100usize);
+}